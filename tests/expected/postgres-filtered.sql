@@ -0,0 +1,19 @@
+BEGIN;
+CREATE TABLE IF NOT EXISTS charging_stations (
+    type TEXT NOT NULL,
+    id BIGINT NOT NULL,
+    geom geometry(Point, 4326),
+    tags JSONB NOT NULL,
+    timestamp TIMESTAMPTZ,
+    version INTEGER,
+    "user" TEXT,
+    extra JSONB,
+    osm_base TIMESTAMPTZ,
+    seen_at TIMESTAMPTZ NOT NULL,
+    deleted_at TIMESTAMPTZ,
+    PRIMARY KEY (type, id)
+);
+CREATE INDEX IF NOT EXISTS charging_stations_geom ON charging_stations USING GIST (geom);
+INSERT INTO charging_stations VALUES ('node', 1, ST_SetSRID(ST_MakePoint(7.4474, 46.9481), 4326), '{"amenity":"charging_station","socket:type2":"4"}', '2023-02-01T09:00:00Z', 2, 'alice', NULL, '2024-03-01T12:00:00Z', now(), NULL) ON CONFLICT (type, id) DO UPDATE SET "geom" = EXCLUDED."geom", "tags" = EXCLUDED."tags", "timestamp" = EXCLUDED."timestamp", "version" = EXCLUDED."version", "user" = EXCLUDED."user", "extra" = EXCLUDED."extra", "osm_base" = EXCLUDED."osm_base", "seen_at" = EXCLUDED."seen_at", deleted_at = NULL;
+INSERT INTO charging_stations VALUES ('way', 10, ST_SetSRID(ST_MakePoint(8.31, 47.05), 4326), '{"amenity":"charging_station","fee":"no","name":"Parking Charger"}', '2023-08-15T16:30:00Z', 3, 'carol', NULL, '2024-03-01T12:00:00Z', now(), NULL) ON CONFLICT (type, id) DO UPDATE SET "geom" = EXCLUDED."geom", "tags" = EXCLUDED."tags", "timestamp" = EXCLUDED."timestamp", "version" = EXCLUDED."version", "user" = EXCLUDED."user", "extra" = EXCLUDED."extra", "osm_base" = EXCLUDED."osm_base", "seen_at" = EXCLUDED."seen_at", deleted_at = NULL;
+COMMIT;
@@ -0,0 +1,148 @@
+#!/bin/bash
+#
+# Run load-overpass.sh on the fixtures in tests/fixtures and compare its
+# outputs with the files in tests/expected. The Overpass API and psql are
+# replaced by the fakes in tests/bin, so no network or database is needed.
+#
+# Usage: tests/run.sh [--update] [TEST...]
+#
+# Without arguments, all tests are run. With --update, the expected files are
+# overwritten with the current outputs instead.
+
+set -euo pipefail
+
+TESTS_DIR=$(cd "$(dirname "$0")" && pwd)
+SCRIPT="$TESTS_DIR/../load-overpass.sh"
+UPDATE=false
+
+function log() { echo -e "\e[32m$1\e[0m"; }
+function loge() { echo -e "\e[31m$1\e[0m"; }
+
+# Run load-overpass.sh with the given arguments, printing its log on failure.
+function run() {
+    if ! bash "$SCRIPT" "$@" > run.log 2>&1; then
+        cat run.log
+        loge "load-overpass.sh $* failed"
+        return 1
+    fi
+}
+
+# Let the fake Overpass API answer the queries with the given fixtures, in
+# order.
+function responses() {
+    local n=0 fixture
+    for fixture in "$@"; do
+        n=$((n + 1))
+        cp "$TESTS_DIR/fixtures/$fixture" "$FAKE_RESPONSES/$n.json"
+    done
+}
+
+# Compare an output file (second argument) with an expected file (first
+# argument).
+function check() {
+    if [ "$UPDATE" = true ]; then
+        cp "$2" "$TESTS_DIR/expected/$1"
+    else
+        diff -u "$TESTS_DIR/expected/$1" "$2"
+    fi
+}
+
+# Decompress the default output, to compare it independently of the
+# compression.
+function output() {
+    gzip -dc charging-stations-osm.json.gz > output.json
+    echo output.json
+}
+
+# Duplicates (node 1) are dropped, keeping the highest version, and the
+# elements are sorted by type and ID.
+function test_process() {
+    run process "$TESTS_DIR/fixtures/raw.json"
+    check process.json "$(output)"
+}
+
+# The binary formats are compared as hex dumps.
+function test_binary_formats() {
+    local format
+    for format in msgpack cbor pb; do
+        run process "$TESTS_DIR/fixtures/raw.json" --format "$format"
+        gzip -dc "charging-stations-osm.$format.gz" | xxd -p > "$format.hex"
+        check "$format.hex" "$format.hex"
+    done
+}
+
+# Contributor metadata is not output with --strip-user.
+function test_strip_user() {
+    run process "$TESTS_DIR/fixtures/raw-body.json" --strip-user
+    check strip-user.json "$(output)"
+}
+
+# The changes since the previous output are merged into it, including ways
+# whose nodes moved (way 10), and elements that no longer exist (node 5) are
+# removed.
+function test_update() {
+    responses update-changes.json update-ids.json
+    run --update "$TESTS_DIR/fixtures/previous.json"
+    check update.json "$(output)"
+    check update-requests.txt "$FAKE_RESPONSES/requests"
+}
+
+# A mirror rejecting the query with a client error other than 400 is skipped.
+function test_failover() {
+    responses raw.json raw.json
+    echo 403 > "$FAKE_RESPONSES/1.status"
+    run --overpass-api-endpoint https://a.example.com/api/interpreter,https://b.example.com/api/interpreter
+    check process.json "$(output)"
+    check failover-requests.txt "$FAKE_RESPONSES/requests"
+}
+
+# A full run marks the elements missing from it as deleted.
+function test_postgres() {
+    run process "$TESTS_DIR/fixtures/raw.json" --output postgres://evmap@localhost/evmap
+    check postgres.sql "$FAKE_PSQL_OUT"
+}
+
+# Filtered runs only upsert the elements they found and leave the others (here
+# the private node 2) alone.
+function test_postgres_filtered() {
+    run process "$TESTS_DIR/fixtures/raw.json" --output postgres://evmap@localhost/evmap --public-only
+    check postgres-filtered.sql "$FAKE_PSQL_OUT"
+    if grep -q '^UPDATE' "$FAKE_PSQL_OUT"; then
+        loge "Filtered run marks elements as deleted"
+        return 1
+    fi
+}
+
+if [ "${1:-}" = --update ]; then
+    UPDATE=true
+    shift
+fi
+if [ $# -gt 0 ]; then
+    tests=("$@")
+else
+    mapfile -t tests < <(declare -F | sed -n 's/^declare -f test_//p')
+fi
+
+failed=()
+for test in "${tests[@]}"; do
+    work=$(mktemp -d)
+    mkdir "$work/responses"
+    if (
+        cd "$work"
+        export PATH="$TESTS_DIR/bin:$PATH"
+        export FAKE_RESPONSES="$work/responses" FAKE_PSQL_OUT="$work/psql.sql"
+        "test_$test"
+    ); then
+        log "ok: $test"
+    else
+        loge "FAILED: $test"
+        failed+=("$test")
+    fi
+    rm -rf "$work"
+done
+
+if [ ${#failed[@]} -gt 0 ]; then
+    loge "${#failed[@]} of ${#tests[@]} tests failed: ${failed[*]}"
+    exit 1
+fi
+log "All ${#tests[@]} tests passed"